@@ -1,6 +1,6 @@
 use std::{
     cmp, fs,
-    io::{self, Write},
+    io::{self, Read, Write},
     os::unix::prelude::FileExt,
     path, sync,
     sync::mpsc::{self, Receiver},
@@ -8,24 +8,46 @@ use std::{
     time::Instant,
 };
 
-use crossterm::{
-    cursor,
-    style::{Color, Stylize},
-    terminal, ExecutableCommand,
-};
+use crc32fast::Hasher as Crc32Hasher;
+use crossbeam_queue::ArrayQueue;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use rand::seq::SliceRandom;
 
 use clap::Parser;
 
+/// Magic number identifying a `cp --compress` pack file.
+const PACK_MAGIC: u64 = 0x4350_5041_434b_3031; // "CPPACK01" in ASCII hex
+const PACK_FORMAT_VERSION: u32 = 1;
+/// `magic: u64` + `version: u32` + `original_len: u64` + `buffer_size: u32`
+const PACK_HEADER_LEN: u64 = 8 + 4 + 8 + 4;
+/// `source_offset: u64` + `compressed_len: u32`, followed by `compressed_len` bytes of data.
+const PACK_RECORD_HEADER_LEN: u64 = 8 + 4;
+
+/// XORed into every CRC32 computed during the `--verify` source pass, so the source-pass and
+/// target-pass CRCs of the same bytes never carry the same stored value.
+const SOURCE_CRC_SALT: u32 = 0x5a17_0001;
+/// Counterpart to `SOURCE_CRC_SALT`, used when recomputing CRCs from the target file.
+const TARGET_CRC_SALT: u32 = 0x5a17_0002;
+
+fn crc32_of(buffer: &[u8]) -> u32 {
+    let mut hasher = Crc32Hasher::new();
+    hasher.update(buffer);
+    hasher.finalize()
+}
+
 struct Config {
     thread_count: u8,
     buffer_size: usize,
+    verify: bool,
 }
 
 impl Config {
-    pub fn new(thread_count: Option<u8>, buffer_size: Option<usize>) -> Self {
+    pub fn new(thread_count: Option<u8>, buffer_size: Option<usize>, verify: bool) -> Self {
         Config {
             thread_count: thread_count.unwrap_or(1),
             buffer_size: buffer_size.unwrap_or(1024),
+            verify,
         }
     }
 }
@@ -36,15 +58,17 @@ struct Status {
     _thread_idx: u8,
     bytes_written: usize,
     offset: u64,
+    crc: u32,
 }
 
 impl Status {
-    fn new(thread_idx: u8, bytes_written: usize, offset: u64) -> Self {
+    fn new(thread_idx: u8, bytes_written: usize, offset: u64, crc: u32) -> Self {
         Status {
             _timestamp: chrono::offset::Local::now().timestamp_millis(),
             _thread_idx: thread_idx,
             bytes_written,
             offset,
+            crc,
         }
     }
 }
@@ -68,90 +92,456 @@ struct Args {
     /// Buffer size
     #[clap(short, long, default_value_t = 10000)]
     buffer_size: usize,
+
+    /// Write the target as a compressed pack file instead of a raw byte copy
+    #[clap(long)]
+    compress: bool,
+
+    /// Restore a target previously written with --compress
+    #[clap(long)]
+    decompress: bool,
+
+    /// Recompute and compare a CRC32 per chunk between source and target after copying
+    #[clap(long)]
+    verify: bool,
 }
 
 fn main() -> io::Result<()> {
     let args = Args::parse();
     let start = Instant::now();
-    cp(
-        path::Path::new(&args.source_filename),
-        path::Path::new(&args.target_filename),
-        Config::new(Some(args.thread_count), Some(args.buffer_size)),
-    )?;
+    let source = path::Path::new(&args.source_filename);
+    let target = path::Path::new(&args.target_filename);
+    let config = Config::new(Some(args.thread_count), Some(args.buffer_size), args.verify);
+
+    if args.compress {
+        cp_compress(source, target, config)?;
+    } else if args.decompress {
+        cp_decompress(source, target, config)?;
+    } else {
+        cp(source, target, config)?;
+    }
+
     let duration = Instant::elapsed(&start).as_millis();
     println!("Done in {duration}ms");
     Ok(())
 }
 
+/// Splits `source_file_len` into `(start_offset, end_offset)` chunks, each spanning at most
+/// `chunk_size` bytes, then shuffles the list so that spatially adjacent chunks aren't handed
+/// out back-to-back. This spreads hot/slow regions of the file across the thread pool instead
+/// of letting them pile up on whichever thread happens to reach them last.
+fn build_shuffled_chunks(source_file_len: u64, chunk_size: u64) -> Vec<(u64, u64)> {
+    let mut chunks = Vec::new();
+    let mut offset = 0;
+    while offset < source_file_len {
+        let end = cmp::min(offset + chunk_size, source_file_len);
+        chunks.push((offset, end));
+        offset = end;
+    }
+    chunks.shuffle(&mut rand::thread_rng());
+    chunks
+}
+
+/// Computes the work-stealing chunk size for a file of `source_file_len` bytes copied with
+/// `thread_count` threads reading `buffer_size` bytes at a time, then builds a shuffled,
+/// pre-filled queue of `(start_offset, end_offset)` chunks ready for workers to pop from.
+fn build_chunk_queue(
+    source_file_len: u64,
+    buffer_size: usize,
+    thread_count: u8,
+) -> sync::Arc<ArrayQueue<(u64, u64)>> {
+    let nr_blocks = cmp::max(source_file_len / buffer_size as u64, 1);
+    let blocks_per_chunk = (nr_blocks / (thread_count as u64 * 64)).clamp(128, 4096);
+    let chunk_size = blocks_per_chunk * buffer_size as u64;
+
+    let chunks = build_shuffled_chunks(source_file_len, chunk_size);
+    let queue = sync::Arc::new(ArrayQueue::new(cmp::max(chunks.len(), 1)));
+    for chunk in chunks {
+        queue.push(chunk).unwrap();
+    }
+
+    println!("chunk_size: {chunk_size} bytes, {} chunks", queue.len());
+    queue
+}
+
 fn cp(source: &path::Path, target: &path::Path, config: Config) -> io::Result<()> {
     let Config {
         buffer_size,
         thread_count,
+        verify,
     } = config;
+
+    if thread_count == 1 {
+        return cp_single_threaded(source, target, buffer_size, verify);
+    }
+
     let source_file = sync::Arc::new(fs::OpenOptions::new().read(true).open(source)?);
     let source_file_len = source_file.metadata()?.len();
     let target_file = sync::Arc::new(
         fs::OpenOptions::new()
+            .read(true)
             .write(true)
             .create(true)
             .truncate(true)
             .open(target)?,
     );
-    let mut join_handles = Vec::with_capacity(thread_count as usize);
-    let total_bytes_per_thread = source_file_len.checked_div(thread_count as u64).unwrap();
-    let buffer_size = buffer_size.min(total_bytes_per_thread as usize);
-    println!("total_bytes_per_thread: {total_bytes_per_thread}");
+
+    let queue = build_chunk_queue(source_file_len, buffer_size, thread_count);
     println!("Copying {source_file_len} bytes using {thread_count} threads and a {buffer_size} bytes buffer");
 
+    let mut join_handles = Vec::with_capacity(thread_count as usize);
     let (tx, rx): (mpsc::Sender<Status>, mpsc::Receiver<Status>) = mpsc::channel();
 
     for i in 0..thread_count {
         let c_source_file = sync::Arc::clone(&source_file);
         let c_target_file = sync::Arc::clone(&target_file);
+        let c_queue = sync::Arc::clone(&queue);
         let thread_tx = tx.clone();
 
         join_handles.push(thread::spawn(move || {
             let mut buffer = vec![0; buffer_size];
-            let mut offset = i as u64 * total_bytes_per_thread;
-            let last_byte_index_to_read = if i == thread_count - 1 {
-                source_file_len
-            } else {
-                (i as u64 + 1) * total_bytes_per_thread
-            };
-
-            loop {
-                let bytes_to_read = cmp::min(
-                    buffer_size as i64,
-                    last_byte_index_to_read as i64 - offset as i64,
-                ) as usize;
-
-                if bytes_to_read == 0 {
-                    break;
+
+            while let Some((chunk_start, chunk_end)) = c_queue.pop() {
+                let mut offset = chunk_start;
+
+                loop {
+                    let bytes_to_read =
+                        cmp::min(buffer_size as u64, chunk_end - offset) as usize;
+
+                    if bytes_to_read == 0 {
+                        break;
+                    }
+
+                    let bytes_read = c_source_file
+                        .read_at(&mut buffer[0..bytes_to_read], offset)
+                        .unwrap();
+
+                    if bytes_read == 0 {
+                        break;
+                    }
+
+                    let bytes_written = c_target_file
+                        .write_at(&buffer[0..bytes_read], offset)
+                        .unwrap();
+
+                    let crc = if verify {
+                        crc32_of(&buffer[0..bytes_read]) ^ SOURCE_CRC_SALT
+                    } else {
+                        0
+                    };
+
+                    thread_tx
+                        .send(Status::new(i, bytes_written, offset, crc))
+                        .unwrap();
+
+                    offset += bytes_read as u64;
                 }
+            }
+        }));
+    }
 
-                let bytes_read = c_source_file
-                    .read_at(&mut buffer[0..bytes_to_read], offset)
-                    .unwrap();
+    drop(tx);
+    let records = report_status(rx, source_file_len, thread_count)?;
+
+    for jh in join_handles {
+        let _ = jh.join();
+    }
+
+    if verify {
+        verify_target(&target_file, records, buffer_size, thread_count)?;
+    }
+
+    Ok(())
+}
+
+/// `thread_count == 1` is the common case, and the multithreaded path's `Arc`s, `thread::spawn`,
+/// and `mpsc::channel` are pure overhead when there's only one worker. This runs the copy loop
+/// inline on the calling thread instead, updating the progress display directly.
+fn cp_single_threaded(
+    source: &path::Path,
+    target: &path::Path,
+    buffer_size: usize,
+    verify: bool,
+) -> io::Result<()> {
+    let source_file = fs::OpenOptions::new().read(true).open(source)?;
+    let source_file_len = source_file.metadata()?.len();
+    let target_file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(target)?;
 
-                if bytes_read == 0 {
-                    break;
+    println!("Copying {source_file_len} bytes using 1 thread and a {buffer_size} bytes buffer");
+
+    let reporter = ProgressReporter::new(source_file_len, 1);
+    let mut buffer = vec![0; buffer_size];
+    let mut offset = 0;
+    let mut records = Vec::new();
+
+    loop {
+        let bytes_to_read = cmp::min(buffer_size as u64, source_file_len - offset) as usize;
+
+        if bytes_to_read == 0 {
+            break;
+        }
+
+        let bytes_read = source_file.read_at(&mut buffer[0..bytes_to_read], offset)?;
+
+        if bytes_read == 0 {
+            break;
+        }
+
+        let bytes_written = target_file.write_at(&buffer[0..bytes_read], offset)?;
+
+        let crc = if verify {
+            crc32_of(&buffer[0..bytes_read]) ^ SOURCE_CRC_SALT
+        } else {
+            0
+        };
+
+        reporter.record(0, bytes_written);
+        records.push((offset, bytes_written, crc));
+
+        offset += bytes_read as u64;
+    }
+
+    reporter.finish();
+
+    if verify {
+        verify_target(&sync::Arc::new(target_file), records, buffer_size, 1)?;
+    }
+
+    Ok(())
+}
+
+/// Re-reads every `(offset, len, crc)` chunk recorded during the `cp()` source pass back from
+/// `target_file`, recomputes its CRC32, and reports any chunk whose target bytes don't match
+/// what was read from the source. Run after the copy's `join_handles` complete so the target
+/// file is fully written.
+fn verify_target(
+    target_file: &sync::Arc<fs::File>,
+    records: Vec<(u64, usize, u32)>,
+    buffer_size: usize,
+    thread_count: u8,
+) -> io::Result<()> {
+    let queue = sync::Arc::new(ArrayQueue::new(cmp::max(records.len(), 1)));
+    for record in records {
+        queue.push(record).unwrap();
+    }
+
+    println!("Verifying {} chunks using {thread_count} threads", queue.len());
+
+    let mismatches = sync::Arc::new(sync::Mutex::new(Vec::new()));
+    let mut join_handles = Vec::with_capacity(thread_count as usize);
+
+    for _ in 0..thread_count {
+        let c_target_file = sync::Arc::clone(target_file);
+        let c_queue = sync::Arc::clone(&queue);
+        let c_mismatches = sync::Arc::clone(&mismatches);
+
+        join_handles.push(thread::spawn(move || {
+            let mut buffer = vec![0; buffer_size];
+
+            while let Some((offset, len, source_crc)) = c_queue.pop() {
+                let bytes_read = c_target_file.read_at(&mut buffer[0..len], offset).unwrap();
+                let target_crc = crc32_of(&buffer[0..bytes_read]) ^ TARGET_CRC_SALT;
+
+                if (source_crc ^ SOURCE_CRC_SALT) != (target_crc ^ TARGET_CRC_SALT) {
+                    c_mismatches.lock().unwrap().push(offset);
                 }
+            }
+        }));
+    }
 
-                let bytes_written = c_target_file
-                    .write_at(&buffer[0..bytes_read], offset)
-                    .unwrap();
+    for jh in join_handles {
+        let _ = jh.join();
+    }
 
-                thread_tx
-                    .send(Status::new(i, bytes_written, offset))
-                    .unwrap();
+    let mismatches = mismatches.lock().unwrap();
+    if mismatches.is_empty() {
+        println!("Verify OK: all chunks match");
+        return Ok(());
+    }
+
+    for offset in mismatches.iter() {
+        eprintln!("verify: mismatch at offset {offset}");
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("verify failed: {} chunk(s) mismatched", mismatches.len()),
+    ))
+}
+
+const AGGREGATE_BAR_TEMPLATE: &str =
+    "{msg:>10} [{bar:40.green/black}] {percent:>3}% {bytes}/{total_bytes} ({bytes_per_sec}, {eta})";
+const THREAD_BAR_TEMPLATE: &str =
+    "{msg:>10} [{bar:40.cyan/black}] {percent:>3}% {bytes}/{total_bytes} ({bytes_per_sec})";
+
+/// One `MultiProgress` holding an aggregate bar for the whole file plus one bar per worker
+/// thread. Worker byte spans aren't known ahead of time (chunks are stolen dynamically, see
+/// `build_chunk_queue`), so each thread bar starts sized to an even share of the file and
+/// grows its length on demand if that thread ends up doing more than its share.
+struct ProgressReporter {
+    aggregate: ProgressBar,
+    per_thread: Vec<ProgressBar>,
+    _multi: MultiProgress,
+}
+
+impl ProgressReporter {
+    fn new(total_len: u64, thread_count: u8) -> Self {
+        let multi = MultiProgress::new();
+
+        let aggregate = multi.add(ProgressBar::new(total_len));
+        aggregate.set_style(ProgressStyle::with_template(AGGREGATE_BAR_TEMPLATE).unwrap());
+        aggregate.set_message("total");
+
+        let thread_style = ProgressStyle::with_template(THREAD_BAR_TEMPLATE).unwrap();
+        let even_share = cmp::max(total_len / thread_count as u64, 1);
+        let per_thread = (0..thread_count)
+            .map(|i| {
+                let bar = multi.add(ProgressBar::new(even_share));
+                bar.set_style(thread_style.clone());
+                bar.set_message(format!("thread {i}"));
+                bar
+            })
+            .collect();
+
+        ProgressReporter {
+            aggregate,
+            per_thread,
+            _multi: multi,
+        }
+    }
+
+    fn record(&self, thread_idx: u8, bytes_written: usize) {
+        let bar = &self.per_thread[thread_idx as usize];
+        let new_position = bar.position() + bytes_written as u64;
+        if new_position > bar.length().unwrap_or(0) {
+            bar.set_length(new_position);
+        }
+        bar.set_position(new_position);
 
-                offset += bytes_read as u64;
+        self.aggregate.inc(bytes_written as u64);
+    }
+
+    fn finish(&self) {
+        for bar in &self.per_thread {
+            bar.finish();
+        }
+        self.aggregate.finish();
+    }
+}
+
+/// Drains `rx`, driving live per-thread and aggregate progress bars for each `Status` as it
+/// arrives, and returns every `(offset, bytes_written, crc)` seen so callers that need it
+/// (e.g. `--verify`) don't have to drain the channel themselves.
+fn report_status(
+    rx: Receiver<Status>,
+    source_file_len: u64,
+    thread_count: u8,
+) -> io::Result<Vec<(u64, usize, u32)>> {
+    let reporter = ProgressReporter::new(source_file_len, thread_count);
+    let mut records = Vec::new();
+
+    for status in rx {
+        reporter.record(status._thread_idx, status.bytes_written);
+        records.push((status.offset, status.bytes_written, status.crc));
+    }
+
+    reporter.finish();
+
+    Ok(records)
+}
+
+/// A single compressed buffer produced by a `cp_compress` worker, still tagged with the
+/// original (uncompressed) source offset so the record stream stays reconstructable even
+/// though threads finish their chunks out of order.
+struct CompressedRecord {
+    thread_idx: u8,
+    source_offset: u64,
+    bytes_read: usize,
+    data: Vec<u8>,
+}
+
+fn cp_compress(source: &path::Path, target: &path::Path, config: Config) -> io::Result<()> {
+    let Config {
+        buffer_size,
+        thread_count,
+        ..
+    } = config;
+    let source_file = sync::Arc::new(fs::OpenOptions::new().read(true).open(source)?);
+    let source_file_len = source_file.metadata()?.len();
+    let target_file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(target)?;
+
+    let mut header = Vec::with_capacity(PACK_HEADER_LEN as usize);
+    header.extend_from_slice(&PACK_MAGIC.to_le_bytes());
+    header.extend_from_slice(&PACK_FORMAT_VERSION.to_le_bytes());
+    header.extend_from_slice(&source_file_len.to_le_bytes());
+    header.extend_from_slice(&(buffer_size as u32).to_le_bytes());
+    target_file.write_all_at(&header, 0)?;
+
+    let queue = build_chunk_queue(source_file_len, buffer_size, thread_count);
+
+    println!("Compressing {source_file_len} bytes using {thread_count} threads and a {buffer_size} bytes buffer");
+
+    let mut join_handles = Vec::with_capacity(thread_count as usize);
+    let (tx, rx): (mpsc::Sender<CompressedRecord>, mpsc::Receiver<CompressedRecord>) =
+        mpsc::channel();
+
+    for i in 0..thread_count {
+        let c_source_file = sync::Arc::clone(&source_file);
+        let c_queue = sync::Arc::clone(&queue);
+        let thread_tx = tx.clone();
+
+        join_handles.push(thread::spawn(move || {
+            let mut buffer = vec![0; buffer_size];
+
+            while let Some((chunk_start, chunk_end)) = c_queue.pop() {
+                let mut offset = chunk_start;
+
+                loop {
+                    let bytes_to_read =
+                        cmp::min(buffer_size as u64, chunk_end - offset) as usize;
+
+                    if bytes_to_read == 0 {
+                        break;
+                    }
+
+                    let bytes_read = c_source_file
+                        .read_at(&mut buffer[0..bytes_to_read], offset)
+                        .unwrap();
+
+                    if bytes_read == 0 {
+                        break;
+                    }
+
+                    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                    encoder.write_all(&buffer[0..bytes_read]).unwrap();
+                    let data = encoder.finish().unwrap();
+
+                    thread_tx
+                        .send(CompressedRecord {
+                            thread_idx: i,
+                            source_offset: offset,
+                            bytes_read,
+                            data,
+                        })
+                        .unwrap();
+
+                    offset += bytes_read as u64;
+                }
             }
         }));
     }
 
     drop(tx);
-    report_status(rx, source_file_len)?;
+    write_compressed_records(rx, target_file, source_file_len, thread_count)?;
 
     for jh in join_handles {
         let _ = jh.join();
@@ -160,33 +550,122 @@ fn cp(source: &path::Path, target: &path::Path, config: Config) -> io::Result<()
     Ok(())
 }
 
-fn report_status(rx: Receiver<Status>, source_file_len: u64) -> io::Result<()> {
-    const BLOCK: &str = "\u{2596}";
+/// Single-writer loop that appends each compressed record to `target_file` right after the
+/// pack header, framing it as `(source_offset: u64, compressed_len: u32, data)`. Running the
+/// append through one thread keeps the record stream a flat, recoverable sequence even though
+/// records arrive out of source order.
+fn write_compressed_records(
+    rx: Receiver<CompressedRecord>,
+    target_file: fs::File,
+    source_file_len: u64,
+    thread_count: u8,
+) -> io::Result<()> {
+    let reporter = ProgressReporter::new(source_file_len, thread_count);
+    let mut write_offset = PACK_HEADER_LEN;
 
-    let terminal_col_count = terminal::size()?.0;
-    let block = BLOCK.with(Color::DarkGreen);
-    io::stdout().execute(cursor::Hide)?;
-    io::stdout().execute(cursor::SavePosition)?;
+    for record in rx {
+        let mut framed = Vec::with_capacity(PACK_RECORD_HEADER_LEN as usize + record.data.len());
+        framed.extend_from_slice(&record.source_offset.to_le_bytes());
+        framed.extend_from_slice(&(record.data.len() as u32).to_le_bytes());
+        framed.extend_from_slice(&record.data);
 
-    let (col, row) = cursor::position()?;
+        target_file.write_all_at(&framed, write_offset)?;
+        write_offset += framed.len() as u64;
 
-    for status in rx {
-        let col = col
-            + (terminal_col_count as f64 * (status.offset as f64 / source_file_len as f64)) as u16;
-        let count = (terminal_col_count as f64
-            * (status.bytes_written as f64 / source_file_len as f64)) as usize;
-        let count = count.max(1);
-
-        io::stdout().execute(crossterm::cursor::MoveTo(col, row))?;
-        for _ in 0..count {
-            print!("{block}");
-        }
-        io::stdout().flush()?;
+        reporter.record(record.thread_idx, record.bytes_read);
+    }
+
+    reporter.finish();
+
+    Ok(())
+}
+
+fn cp_decompress(source: &path::Path, target: &path::Path, config: Config) -> io::Result<()> {
+    let Config { thread_count, .. } = config;
+    let pack_file = fs::OpenOptions::new().read(true).open(source)?;
+    let pack_len = pack_file.metadata()?.len();
+
+    let mut header = [0u8; PACK_HEADER_LEN as usize];
+    pack_file.read_exact_at(&mut header, 0)?;
+
+    let magic = u64::from_le_bytes(header[0..8].try_into().unwrap());
+    let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+    let original_len = u64::from_le_bytes(header[12..20].try_into().unwrap());
+
+    if magic != PACK_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "source is not a cp --compress pack file",
+        ));
+    }
+    if version != PACK_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported pack format version {version}"),
+        ));
+    }
+
+    let mut records = Vec::new();
+    let mut read_offset = PACK_HEADER_LEN;
+    while read_offset < pack_len {
+        let mut record_header = [0u8; PACK_RECORD_HEADER_LEN as usize];
+        pack_file.read_exact_at(&mut record_header, read_offset)?;
+        let source_offset = u64::from_le_bytes(record_header[0..8].try_into().unwrap());
+        let compressed_len = u32::from_le_bytes(record_header[8..12].try_into().unwrap()) as usize;
+
+        let mut data = vec![0; compressed_len];
+        pack_file.read_exact_at(&mut data, read_offset + PACK_RECORD_HEADER_LEN)?;
+
+        records.push((source_offset, data));
+        read_offset += PACK_RECORD_HEADER_LEN + compressed_len as u64;
+    }
+
+    let target_file = sync::Arc::new(
+        fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(target)?,
+    );
+    target_file.set_len(original_len)?;
+
+    let queue = sync::Arc::new(ArrayQueue::new(cmp::max(records.len(), 1)));
+    for record in records {
+        queue.push(record).unwrap();
+    }
+
+    println!("Decompressing into {original_len} bytes using {thread_count} threads");
+
+    let mut join_handles = Vec::with_capacity(thread_count as usize);
+    let (tx, rx): (mpsc::Sender<Status>, mpsc::Receiver<Status>) = mpsc::channel();
+
+    for i in 0..thread_count {
+        let c_target_file = sync::Arc::clone(&target_file);
+        let c_queue = sync::Arc::clone(&queue);
+        let thread_tx = tx.clone();
+
+        join_handles.push(thread::spawn(move || {
+            while let Some((source_offset, data)) = c_queue.pop() {
+                let mut decoded = Vec::new();
+                ZlibDecoder::new(&data[..])
+                    .read_to_end(&mut decoded)
+                    .unwrap();
+
+                let bytes_written = c_target_file.write_at(&decoded, source_offset).unwrap();
+
+                thread_tx
+                    .send(Status::new(i, bytes_written, source_offset, 0))
+                    .unwrap();
+            }
+        }));
     }
 
-    io::stdout().execute(crossterm::cursor::RestorePosition)?;
-    println!();
-    io::stdout().execute(cursor::Show)?;
+    drop(tx);
+    report_status(rx, original_len, thread_count)?;
+
+    for jh in join_handles {
+        let _ = jh.join();
+    }
 
     Ok(())
 }